@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::InterfaceType;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct BaseInterface {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub iface_type: InterfaceType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<InterfaceState>,
+    /// RFC 2863 operational state of the interface as last reported by the
+    /// kernel, kept distinct from the administrative/desired
+    /// [Self::state]. Only ever set by [crate::NetworkState::retrieve()];
+    /// `#[serde(skip_deserializing)]` enforces that it cannot be configured.
+    /// A [BaseInterface] deserialized on its own rejects `oper-state` as an
+    /// unknown field; where it is `#[serde(flatten)]`ed into a per-type
+    /// interface (e.g. [crate::EthernetInterface]) the key is ignored
+    /// instead. Either way it plays no part in verification or merging.
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub oper_state: Option<InterfaceOperState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_mtu: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipv4: Option<InterfaceIpv4>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<InterfaceIpv6>,
+}
+
+impl BaseInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets [Self::oper_state] from a value retrieved from the kernel.
+    /// The only intended caller is [crate::NetworkState::retrieve()]; it
+    /// bypasses `oper_state`'s `#[serde(skip_deserializing)]` guard, which
+    /// exists only to keep `oper-state` out of desired-state documents.
+    pub(crate) fn set_oper_state(&mut self, oper_state: InterfaceOperState) {
+        self.oper_state = Some(oper_state);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct InterfaceIpv4 {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub dhcp: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub address: Vec<InterfaceIpAddr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct InterfaceIpv6 {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub dhcp: bool,
+    #[serde(default)]
+    pub autoconf: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub address: Vec<InterfaceIpAddr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct InterfaceIpAddr {
+    pub ip: String,
+    pub prefix_length: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum InterfaceState {
+    Up,
+    Down,
+    Absent,
+    Ignore,
+}
+
+/// RFC 2863 `ifOperStatus`, reported on query only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum InterfaceOperState {
+    Up,
+    Down,
+    /// The interface is admin-up but a lower-layer interface it depends on
+    /// (e.g. the base interface of a [crate::VlanInterface]) is down.
+    LowerLayerDown,
+    Testing,
+    Dormant,
+    NotPresent,
+    Unknown,
+}
+
+impl InterfaceOperState {
+    /// Parses the value of `/sys/class/net/<iface>/operstate`, as defined
+    /// by the kernel's `netdevice` documentation. Unrecognized values
+    /// (including those from a kernel newer than this crate) map to
+    /// [Self::Unknown] rather than failing, consistent with how this
+    /// crate treats unrecognized `type` values in [crate::InterfaceType].
+    pub(crate) fn parse_kernel_operstate(raw: &str) -> Self {
+        match raw.trim() {
+            "up" => Self::Up,
+            "down" => Self::Down,
+            "lowerlayerdown" => Self::LowerLayerDown,
+            "testing" => Self::Testing,
+            "dormant" => Self::Dormant,
+            "notpresent" => Self::NotPresent,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_kernel_operstate_values() {
+        assert_eq!(InterfaceOperState::parse_kernel_operstate("up"), InterfaceOperState::Up);
+        assert_eq!(InterfaceOperState::parse_kernel_operstate("down"), InterfaceOperState::Down);
+        assert_eq!(
+            InterfaceOperState::parse_kernel_operstate("lowerlayerdown"),
+            InterfaceOperState::LowerLayerDown
+        );
+        assert_eq!(InterfaceOperState::parse_kernel_operstate("dormant\n"), InterfaceOperState::Dormant);
+    }
+
+    #[test]
+    fn unrecognized_kernel_operstate_maps_to_unknown() {
+        assert_eq!(
+            InterfaceOperState::parse_kernel_operstate("some-future-state"),
+            InterfaceOperState::Unknown
+        );
+    }
+
+    #[test]
+    fn oper_state_is_rejected_from_desired_state_yaml() {
+        let err = serde_yaml::from_str::<BaseInterface>(
+            "name: eth1\ntype: ethernet\noper-state: down\n",
+        )
+        .unwrap_err();
+        assert!(format!("{err}").contains("oper-state") || format!("{err}").contains("unknown field"));
+    }
+
+    #[test]
+    fn set_oper_state_populates_the_field() {
+        let mut base = BaseInterface::new();
+        assert_eq!(base.oper_state, None);
+        base.set_oper_state(InterfaceOperState::Up);
+        assert_eq!(base.oper_state, Some(InterfaceOperState::Up));
+    }
+}
@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Renders a [crate::NetworkState] to systemd-networkd `.network`/`.netdev`
+//! units, as an alternative to the NetworkManager keyfiles produced by the
+//! default [crate::NetworkState::gen_conf()] backend.
+
+#![cfg(feature = "gen_conf")]
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::{BaseInterface, Interface, NetworkState, NmstateError, Route};
+
+/// Render every interface in `net_state` to `(file_name, file_content)`
+/// pairs covering the `.netdev`/`.network` units systemd-networkd needs to
+/// reproduce the desired state offline.
+pub(crate) fn gen_networkd_confs(
+    net_state: &NetworkState,
+) -> Result<Vec<(String, String)>, NmstateError> {
+    let dns_servers: Vec<String> = net_state
+        .dns
+        .as_ref()
+        .and_then(|dns| dns.config.as_ref())
+        .map(|cfg| cfg.server.clone())
+        .unwrap_or_default();
+
+    // Parent interface name -> names of tunnels bound to it, so the
+    // parent's own `.network` unit can carry the `Tunnel=` directive
+    // systemd-networkd requires to start the tunnel alongside it.
+    let mut bound_tunnels: HashMap<&str, Vec<&str>> = HashMap::new();
+    for iface in net_state.interfaces.iter() {
+        if let Interface::Tunnel(tunnel_iface) = iface {
+            if let Some(parent) = tunnel_iface.parent() {
+                bound_tunnels
+                    .entry(parent)
+                    .or_default()
+                    .push(tunnel_iface.base.name.as_str());
+            }
+        }
+    }
+
+    let mut ret = Vec::new();
+    for iface in net_state.interfaces.iter() {
+        let base = iface.base_iface();
+
+        match iface {
+            Interface::Vlan(vlan_iface) => {
+                if let Some(vlan_conf) = vlan_iface.vlan.as_ref() {
+                    ret.push((
+                        format!("{}.netdev", base.name),
+                        gen_vlan_netdev(base, vlan_conf),
+                    ));
+                }
+            }
+            Interface::Tunnel(tunnel_iface) => {
+                if let Some(tunnel_conf) = tunnel_iface.tunnel.as_ref() {
+                    ret.push((
+                        format!("{}.netdev", base.name),
+                        gen_tunnel_netdev(base, tunnel_conf),
+                    ));
+                }
+            }
+            _ => (),
+        }
+
+        let routes: Vec<&Route> = net_state
+            .routes
+            .config
+            .iter()
+            .filter(|route| route.next_hop_iface == base.name)
+            .collect();
+
+        let tunnels = bound_tunnels
+            .get(base.name.as_str())
+            .map(|v| v.as_slice())
+            .unwrap_or_default();
+
+        ret.push((
+            format!("{}.network", base.name),
+            gen_network_unit(base, &dns_servers, &routes, tunnels),
+        ));
+    }
+    Ok(ret)
+}
+
+fn gen_vlan_netdev(
+    base: &BaseInterface,
+    vlan_conf: &crate::VlanConfig,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "[NetDev]");
+    let _ = writeln!(out, "Name={}", base.name);
+    let _ = writeln!(out, "Kind=vlan");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "[VLAN]");
+    let _ = writeln!(out, "Id={}", vlan_conf.id);
+    if let Some(protocol) = vlan_conf.protocol {
+        let _ = writeln!(out, "Protocol={protocol}");
+    }
+    match vlan_conf.registration_protocol {
+        Some(crate::VlanRegistrationProtocol::Gvrp) => {
+            let _ = writeln!(out, "GVRP=true");
+        }
+        Some(crate::VlanRegistrationProtocol::Mvrp) => {
+            let _ = writeln!(out, "MVRP=true");
+        }
+        Some(crate::VlanRegistrationProtocol::None) | None => {}
+    }
+    if let Some(reorder) = vlan_conf.reorder_headers {
+        let _ = writeln!(out, "ReorderHeaders={reorder}");
+    }
+    if let Some(loose_binding) = vlan_conf.loose_binding {
+        let _ = writeln!(out, "LooseBinding={loose_binding}");
+    }
+    out
+}
+
+fn gen_tunnel_netdev(
+    base: &BaseInterface,
+    tunnel_conf: &crate::TunnelConfig,
+) -> String {
+    let kind = match base.iface_type {
+        crate::InterfaceType::Gre => "gre",
+        crate::InterfaceType::Gre6 => "ip6gre",
+        crate::InterfaceType::IpIp => "ipip",
+        crate::InterfaceType::Sit => "sit",
+        _ => "gre",
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "[NetDev]");
+    let _ = writeln!(out, "Name={}", base.name);
+    let _ = writeln!(out, "Kind={kind}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "[Tunnel]");
+    if tunnel_conf.base_iface.is_some() {
+        // The parent's own `.network` unit carries the matching
+        // `Tunnel=` directive (see gen_network_unit()) that actually
+        // binds this netdev to it; `Independent=false` just tells
+        // systemd-networkd not to bring the tunnel up before that
+        // parent exists.
+        let _ = writeln!(out, "Independent=false");
+    }
+    let _ = writeln!(out, "Local={}", tunnel_conf.local);
+    let _ = writeln!(out, "Remote={}", tunnel_conf.remote);
+    if let Some(ttl) = tunnel_conf.ttl {
+        let _ = writeln!(out, "TTL={ttl}");
+    }
+    if let Some(key) = tunnel_conf.input_key.or(tunnel_conf.key) {
+        let _ = writeln!(out, "InputKey={key}");
+    }
+    if let Some(key) = tunnel_conf.output_key.or(tunnel_conf.key) {
+        let _ = writeln!(out, "OutputKey={key}");
+    }
+    out
+}
+
+fn gen_network_unit(
+    base: &BaseInterface,
+    dns_servers: &[String],
+    routes: &[&Route],
+    bound_tunnels: &[&str],
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "[Match]");
+    let _ = writeln!(out, "Name={}", base.name);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "[Link]");
+    if let Some(mtu) = base.mtu {
+        let _ = writeln!(out, "MTUBytes={mtu}");
+    }
+    if let Some(mac_address) = base.mac_address.as_ref() {
+        let _ = writeln!(out, "MACAddress={mac_address}");
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "[Network]");
+    let has_ip_config = base
+        .ipv4
+        .as_ref()
+        .map(|ipv4| ipv4.enabled)
+        .unwrap_or_default()
+        || base
+            .ipv6
+            .as_ref()
+            .map(|ipv6| ipv6.enabled)
+            .unwrap_or_default();
+    if let Some(ipv4) = base.ipv4.as_ref() {
+        if ipv4.dhcp {
+            let _ = writeln!(out, "DHCP=ipv4");
+        }
+        for addr in &ipv4.address {
+            let _ =
+                writeln!(out, "Address={}/{}", addr.ip, addr.prefix_length);
+        }
+    }
+    if let Some(ipv6) = base.ipv6.as_ref() {
+        if ipv6.dhcp || ipv6.autoconf {
+            let _ = writeln!(out, "IPv6AcceptRA=true");
+        }
+        for addr in &ipv6.address {
+            let _ =
+                writeln!(out, "Address={}/{}", addr.ip, addr.prefix_length);
+        }
+    }
+    if has_ip_config {
+        for server in dns_servers {
+            let _ = writeln!(out, "DNS={server}");
+        }
+    }
+    for tunnel_name in bound_tunnels {
+        let _ = writeln!(out, "Tunnel={tunnel_name}");
+    }
+
+    for route in routes {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "[Route]");
+        let _ = writeln!(out, "Destination={}", route.destination);
+        if let Some(gateway) = route.next_hop_address.as_ref() {
+            let _ = writeln!(out, "Gateway={gateway}");
+        }
+        if let Some(metric) = route.metric {
+            let _ = writeln!(out, "Metric={metric}");
+        }
+        if let Some(table_id) = route.table_id {
+            let _ = writeln!(out, "Table={table_id}");
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InterfaceIpv4, InterfaceType, TunnelConfig};
+
+    #[test]
+    fn bound_tunnel_netdev_has_independent_false_and_no_stale_comment() {
+        let base = BaseInterface {
+            name: "gre1".to_string(),
+            iface_type: InterfaceType::Gre,
+            ..Default::default()
+        };
+        let tunnel_conf = TunnelConfig {
+            base_iface: Some("eth1".to_string()),
+            local: "192.0.2.1".to_string(),
+            remote: "192.0.2.2".to_string(),
+            ..Default::default()
+        };
+        let out = gen_tunnel_netdev(&base, &tunnel_conf);
+        assert!(out.contains("Independent=false"));
+        assert!(!out.contains("BindCarrier"));
+    }
+
+    #[test]
+    fn parent_network_unit_gets_tunnel_directive() {
+        let base = BaseInterface {
+            name: "eth1".to_string(),
+            ..Default::default()
+        };
+        let out = gen_network_unit(&base, &[], &[], &["gre1"]);
+        assert!(out.contains("Tunnel=gre1"));
+    }
+
+    #[test]
+    fn dns_is_only_emitted_for_interfaces_with_ip_config() {
+        let base_without_ip = BaseInterface {
+            name: "eth0".to_string(),
+            ..Default::default()
+        };
+        let out = gen_network_unit(
+            &base_without_ip,
+            &["198.51.100.1".to_string()],
+            &[],
+            &[],
+        );
+        assert!(!out.contains("DNS="));
+
+        let base_with_ip = BaseInterface {
+            name: "eth1".to_string(),
+            ipv4: Some(InterfaceIpv4 {
+                enabled: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let out = gen_network_unit(
+            &base_with_ip,
+            &["198.51.100.1".to_string()],
+            &[],
+            &[],
+        );
+        assert!(out.contains("DNS=198.51.100.1"));
+    }
+}
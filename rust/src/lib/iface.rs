@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BaseInterface, BondInterface, DummyInterface, EthernetInterface,
+    InfinibandInterface, LinuxBridgeInterface, LoopbackInterface,
+    MacVlanInterface, MacVtapInterface, OvsBridgeInterface, OvsInterface,
+    TunnelInterface, VethInterface, VlanInterface, VrfInterface,
+    VxlanInterface, XfrmInterface,
+};
+
+/// Dispatches to the per-interface-type configuration modeled by this
+/// crate. [crate::Interfaces] (and therefore [crate::NetworkState]) is
+/// built out of these. Interface types this build does not model yet still
+/// round-trip via [Interface::Unknown] instead of being dropped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum Interface {
+    Ethernet(EthernetInterface),
+    Bond(BondInterface),
+    LinuxBridge(LinuxBridgeInterface),
+    OvsBridge(OvsBridgeInterface),
+    OvsInterface(OvsInterface),
+    Vlan(VlanInterface),
+    Vxlan(VxlanInterface),
+    Dummy(DummyInterface),
+    Veth(VethInterface),
+    Vrf(VrfInterface),
+    MacVlan(MacVlanInterface),
+    MacVtap(MacVtapInterface),
+    Infiniband(InfinibandInterface),
+    Loopback(LoopbackInterface),
+    Xfrm(XfrmInterface),
+    Tunnel(TunnelInterface),
+    Unknown(BaseInterface),
+}
+
+impl Interface {
+    pub fn base_iface(&self) -> &BaseInterface {
+        match self {
+            Self::Ethernet(iface) => &iface.base,
+            Self::Bond(iface) => &iface.base,
+            Self::LinuxBridge(iface) => &iface.base,
+            Self::OvsBridge(iface) => &iface.base,
+            Self::OvsInterface(iface) => &iface.base,
+            Self::Vlan(iface) => &iface.base,
+            Self::Vxlan(iface) => &iface.base,
+            Self::Dummy(iface) => &iface.base,
+            Self::Veth(iface) => &iface.base,
+            Self::Vrf(iface) => &iface.base,
+            Self::MacVlan(iface) => &iface.base,
+            Self::MacVtap(iface) => &iface.base,
+            Self::Infiniband(iface) => &iface.base,
+            Self::Loopback(iface) => &iface.base,
+            Self::Xfrm(iface) => &iface.base,
+            Self::Tunnel(iface) => &iface.base,
+            Self::Unknown(base) => base,
+        }
+    }
+
+    pub(crate) fn base_iface_mut(&mut self) -> &mut BaseInterface {
+        match self {
+            Self::Ethernet(iface) => &mut iface.base,
+            Self::Bond(iface) => &mut iface.base,
+            Self::LinuxBridge(iface) => &mut iface.base,
+            Self::OvsBridge(iface) => &mut iface.base,
+            Self::OvsInterface(iface) => &mut iface.base,
+            Self::Vlan(iface) => &mut iface.base,
+            Self::Vxlan(iface) => &mut iface.base,
+            Self::Dummy(iface) => &mut iface.base,
+            Self::Veth(iface) => &mut iface.base,
+            Self::Vrf(iface) => &mut iface.base,
+            Self::MacVlan(iface) => &mut iface.base,
+            Self::MacVtap(iface) => &mut iface.base,
+            Self::Infiniband(iface) => &mut iface.base,
+            Self::Loopback(iface) => &mut iface.base,
+            Self::Xfrm(iface) => &mut iface.base,
+            Self::Tunnel(iface) => &mut iface.base,
+            Self::Unknown(base) => base,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.base_iface().name.as_str()
+    }
+
+    /// Name of the interface this one depends on (e.g. the base interface
+    /// of a VLAN or tunnel), used by `MergedInterfaces` to order
+    /// activation/merge.
+    pub(crate) fn parent(&self) -> Option<&str> {
+        match self {
+            Self::Vlan(iface) => iface.parent(),
+            Self::Tunnel(iface) => iface.parent(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ethernet_variant_reaches_its_base_iface() {
+        let mut iface = Interface::Ethernet(EthernetInterface::new());
+        iface.base_iface_mut().name = "eth1".to_string();
+        assert_eq!(iface.name(), "eth1");
+        assert_eq!(iface.parent(), None);
+    }
+
+    #[test]
+    fn tunnel_variant_reports_its_parent() {
+        let mut tunnel_iface = TunnelInterface::new();
+        tunnel_iface.tunnel = Some(crate::TunnelConfig {
+            base_iface: Some("eth1".to_string()),
+            local: "192.0.2.1".to_string(),
+            remote: "192.0.2.2".to_string(),
+            ..Default::default()
+        });
+        let iface = Interface::Tunnel(tunnel_iface);
+        assert_eq!(iface.parent(), Some("eth1"));
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum InterfaceType {
+    Ethernet,
+    Bond,
+    LinuxBridge,
+    OvsBridge,
+    OvsInterface,
+    Vlan,
+    Vxlan,
+    Dummy,
+    Veth,
+    Vrf,
+    MacVlan,
+    MacVtap,
+    Infiniband,
+    Loopback,
+    Xfrm,
+    /// GRE tunnel over IPv4.
+    Gre,
+    /// GRE tunnel over IPv6.
+    Gre6,
+    /// IP-in-IP tunnel.
+    IpIp,
+    /// Simple Internet Transition (6in4) tunnel.
+    Sit,
+    #[serde(other)]
+    Unknown,
+}
+
+impl Default for InterfaceType {
+    fn default() -> Self {
+        Self::Unknown
+    }
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaseInterface, InterfaceType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct LinuxBridgeInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+}
+
+impl Default for LinuxBridgeInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::LinuxBridge,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl LinuxBridgeInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
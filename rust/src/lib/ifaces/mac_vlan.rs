@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaseInterface, InterfaceType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MacVlanInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+}
+
+impl Default for MacVlanInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::MacVlan,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl MacVlanInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct MacVtapInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+}
+
+impl Default for MacVtapInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::MacVtap,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl MacVtapInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaseInterface, InterfaceType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct OvsBridgeInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+}
+
+impl Default for OvsBridgeInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::OvsBridge,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl OvsBridgeInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct OvsInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+}
+
+impl Default for OvsInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::OvsInterface,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl OvsInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
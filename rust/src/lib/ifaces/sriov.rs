@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct SrIovConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_vfs: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drivers_autoprobe: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vfs: Option<Vec<SrIovVfConfig>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct SrIovVfConfig {
+    pub id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spoof_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_tx_rate: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tx_rate: Option<u32>,
+    /// VLAN ID of the single VLAN applied to this VF. Shorthand for a
+    /// one-element [Self::vlans]; ignored when `vlans` is also set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vlan_id: Option<u32>,
+    /// QoS priority of [Self::vlan_id]. Ignored when [Self::vlans] is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qos: Option<u32>,
+    #[serde(rename = "vlan-proto", skip_serializing_if = "Option::is_none")]
+    pub vlan_proto: Option<SrIovVfVlanProtocol>,
+    /// Stacked VLANs applied to this VF, for example an outer 802.1ad tag
+    /// plus an inner 802.1q tag with its own QoS priority. When set, this
+    /// takes precedence over the scalar `vlan-id`/`qos`/`vlan-proto`
+    /// shorthand above.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vlans: Option<Vec<SrIovVfVlanConfig>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct SrIovVfVlanConfig {
+    pub id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qos: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<SrIovVfVlanProtocol>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum SrIovVfVlanProtocol {
+    #[serde(rename = "802.1q")]
+    Ieee8021Q,
+    #[serde(rename = "802.1ad")]
+    Ieee8021Ad,
+}
+
+impl Default for SrIovVfVlanProtocol {
+    fn default() -> Self {
+        Self::Ieee8021Q
+    }
+}
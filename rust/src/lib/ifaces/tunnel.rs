@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaseInterface, InterfaceType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+/// Linux kernel tunnel interface: GRE, GRE over IPv6, IP-in-IP or SIT.
+/// The example yaml output of [crate::NetworkState] with a GRE tunnel
+/// interface would be:
+/// ```yaml
+/// interfaces:
+/// - name: gre1
+///   type: gre
+///   state: up
+///   tunnel:
+///     base-iface: eth1
+///     local: 192.0.2.1
+///     remote: 192.0.2.2
+///     ttl: 64
+/// ```
+pub struct TunnelInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tunnel: Option<TunnelConfig>,
+}
+
+impl Default for TunnelInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::Gre,
+                ..Default::default()
+            },
+            tunnel: None,
+        }
+    }
+}
+
+impl TunnelInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn parent(&self) -> Option<&str> {
+        self.tunnel
+            .as_ref()
+            .and_then(|cfg| cfg.base_iface.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct TunnelConfig {
+    /// The underlying interface the tunnel is routed over, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_iface: Option<String>,
+    pub local: String,
+    pub remote: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u8>,
+    /// Tunnel key, used for both input and output unless overridden by
+    /// [Self::input_key]/[Self::output_key].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<u32>,
+    /// GRE-only: overrides [Self::key] for incoming packets.
+    #[serde(rename = "input-key", skip_serializing_if = "Option::is_none")]
+    pub input_key: Option<u32>,
+    /// GRE-only: overrides [Self::key] for outgoing packets.
+    #[serde(rename = "output-key", skip_serializing_if = "Option::is_none")]
+    pub output_key: Option<u32>,
+}
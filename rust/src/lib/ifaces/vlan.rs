@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::{BaseInterface, Interface, InterfaceType, MergedInterface};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 /// Linux kernel VLAN interface. The example yaml output of
 /// [crate::NetworkState] with a VLAN interface would be:
@@ -56,6 +57,7 @@ impl VlanInterface {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 #[non_exhaustive]
 pub struct VlanConfig {
@@ -77,6 +79,7 @@ pub struct VlanConfig {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum VlanProtocol {
     #[serde(rename = "802.1q")]
     /// Deserialize and serialize from/to `802.1q`.
@@ -106,6 +109,7 @@ impl std::fmt::Display for VlanProtocol {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub enum VlanRegistrationProtocol {
     /// GARP VLAN Registration Protocol
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaseInterface, InterfaceType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct VrfInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+}
+
+impl Default for VrfInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::Vrf,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl VrfInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaseInterface, InterfaceType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct VxlanInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+}
+
+impl Default for VxlanInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::Vxlan,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl VxlanInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
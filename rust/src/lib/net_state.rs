@@ -1,6 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(not(feature = "gen_conf"))]
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
@@ -75,9 +74,18 @@ use crate::{
 ///   other_config: {}
 /// ```
 #[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 #[non_exhaustive]
 pub struct NetworkState {
+    /// Schema version of this document. Only required when a desired state
+    /// file was generated against an older release of this crate and relies
+    /// on fields that have since been renamed or reshaped; such documents
+    /// are migrated forward to the current schema before being applied.
+    /// Omitted on serialization when unset; a document parsed with an
+    /// explicit `version` keeps it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
     #[serde(default, skip_serializing_if = "String::is_empty")]
     /// Description for the whole desire state. Currently it will not be
     /// persisted by network backend and will be ignored during applying or
@@ -128,6 +136,7 @@ pub struct NetworkState {
 
 impl NetworkState {
     pub fn is_empty(&self) -> bool {
+        // `version` is metadata about the document, not state.
         self.hostname.is_none()
             && self.dns.is_none()
             && self.ovsdb.is_none()
@@ -217,28 +226,18 @@ impl NetworkState {
         Default::default()
     }
 
-    /// Wrapping function of [serde_json::from_str()] with error mapped to
-    /// [NmstateError].
+    /// Parse a JSON desired-state document, migrating it from its `version`
+    /// (or the current schema version, if unset) up to the schema this
+    /// crate implements. Errors are mapped to [NmstateError].
     pub fn new_from_json(net_state_json: &str) -> Result<Self, NmstateError> {
-        match serde_json::from_str(net_state_json) {
-            Ok(s) => Ok(s),
-            Err(e) => Err(NmstateError::new(
-                ErrorKind::InvalidArgument,
-                format!("Invalid JSON string: {e}"),
-            )),
-        }
+        crate::net_state_version::net_state_from_json_str(net_state_json)
     }
 
-    /// Wrapping function of [serde_yaml::from_str()] with error mapped to
-    /// [NmstateError].
+    /// Parse a YAML desired-state document, migrating it from its `version`
+    /// (or the current schema version, if unset) up to the schema this
+    /// crate implements. Errors are mapped to [NmstateError].
     pub fn new_from_yaml(net_state_yaml: &str) -> Result<Self, NmstateError> {
-        match serde_yaml::from_str(net_state_yaml) {
-            Ok(s) => Ok(s),
-            Err(e) => Err(NmstateError::new(
-                ErrorKind::InvalidArgument,
-                format!("Invalid YAML string: {e}"),
-            )),
-        }
+        crate::net_state_version::net_state_from_yaml_str(net_state_yaml)
     }
 
     /// Append [Interface] into [NetworkState]
@@ -295,6 +294,39 @@ impl NetworkState {
         ))
     }
 
+    /// Like [NetworkState::gen_conf()], but lets the caller pick which
+    /// backend the offline configuration is rendered for instead of always
+    /// producing NetworkManager keyfiles.
+    #[cfg(not(feature = "gen_conf"))]
+    pub fn gen_conf_for(
+        &self,
+        _backend: GenConfBackend,
+    ) -> Result<HashMap<String, Vec<(String, String)>>, NmstateError> {
+        Err(NmstateError::new(
+            ErrorKind::DependencyError,
+            "NetworkState::gen_conf_for() need `gen_conf` feature enabled"
+                .into(),
+        ))
+    }
+
+    #[cfg(feature = "gen_conf")]
+    pub fn gen_conf_for(
+        &self,
+        backend: GenConfBackend,
+    ) -> Result<HashMap<String, Vec<(String, String)>>, NmstateError> {
+        match backend {
+            GenConfBackend::NetworkManager => self.gen_conf(),
+            GenConfBackend::Networkd => {
+                let mut ret = HashMap::new();
+                ret.insert(
+                    "systemd-networkd".to_string(),
+                    crate::gen_conf_networkd::gen_networkd_confs(self)?,
+                );
+                Ok(ret)
+            }
+        }
+    }
+
     #[cfg(not(feature = "query_apply"))]
     pub fn checkpoint_rollback(_checkpoint: &str) -> Result<(), NmstateError> {
         Err(NmstateError::new(
@@ -316,6 +348,38 @@ impl NetworkState {
     }
 }
 
+#[cfg(not(feature = "schema"))]
+impl NetworkState {
+    /// Returns an empty JSON object. Build with the `schema` feature
+    /// enabled to generate the real JSON Schema via
+    /// [NetworkState::json_schema()].
+    pub fn json_schema() -> serde_json::Value {
+        serde_json::Value::Object(Default::default())
+    }
+
+    pub fn validate_against_schema(_doc: &str) -> Result<(), NmstateError> {
+        Err(NmstateError::new(
+            ErrorKind::DependencyError,
+            "NetworkState::validate_against_schema() need `schema` feature \
+             enabled"
+                .into(),
+        ))
+    }
+}
+
+/// Backend to render an offline configuration for via
+/// [NetworkState::gen_conf_for()].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GenConfBackend {
+    /// NetworkManager keyfiles, same output as [NetworkState::gen_conf()].
+    #[default]
+    NetworkManager,
+    /// systemd-networkd `.network`/`.netdev` units; no `.link` units are
+    /// generated.
+    Networkd,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub(crate) struct MergedNetworkState {
     pub(crate) interfaces: MergedInterfaces,
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON Schema (draft 2020-12) generation for [NetworkState], so external
+//! tooling and editors can validate a desired-state document before ever
+//! calling [NetworkState::apply()].
+
+#![cfg(feature = "schema")]
+
+use crate::{ErrorKind, NetworkState, NmstateError};
+
+impl NetworkState {
+    /// Generate a JSON Schema (draft 2020-12) describing the full
+    /// [NetworkState] desired-state document, including interfaces, routes,
+    /// route-rules, dns-resolver, ovs-db, ovn and the per-interface-type
+    /// sub-schemas (e.g. [crate::VlanConfig]). The schema is derived
+    /// directly from the `serde`-annotated structs, so it stays in sync as
+    /// fields are added, renamed or marked `#[non_exhaustive]`.
+    pub fn json_schema() -> serde_json::Value {
+        let schema = schemars::schema_for!(NetworkState);
+        serde_json::to_value(schema).unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Validate `doc` (YAML or JSON) against [NetworkState::json_schema()],
+    /// reporting every violation found instead of failing on the first
+    /// unknown field as `new_from_yaml()`/`new_from_json()` do.
+    ///
+    /// [NetworkState::json_schema()] only describes the current schema
+    /// version, so a `doc` declaring an older `version` is migrated
+    /// forward first, the same way `new_from_yaml()`/`new_from_json()`
+    /// migrate it, to keep the two code paths agreeing on what is valid.
+    pub fn validate_against_schema(doc: &str) -> Result<(), NmstateError> {
+        let value: serde_json::Value = if let Ok(v) =
+            serde_json::from_str::<serde_json::Value>(doc)
+        {
+            v
+        } else {
+            let yaml_value: serde_yaml::Value = serde_yaml::from_str(doc)
+                .map_err(|e| {
+                    NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!("Invalid YAML/JSON string: {e}"),
+                    )
+                })?;
+            serde_json::to_value(yaml_value).map_err(|e| {
+                NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!("Invalid YAML/JSON string: {e}"),
+                )
+            })?
+        };
+
+        let is_current_version = value
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .map_or(true, |v| {
+                v == u64::from(crate::net_state_version::CURRENT_SCHEMA_VERSION)
+            });
+        let value = if is_current_version {
+            value
+        } else {
+            let net_state =
+                crate::net_state_version::net_state_from_value(value)?;
+            serde_json::to_value(&net_state).map_err(|e| {
+                NmstateError::new(
+                    ErrorKind::Bug,
+                    format!("Failed to re-serialize migrated NetworkState: {e}"),
+                )
+            })?
+        };
+
+        let schema = Self::json_schema();
+        let validator =
+            jsonschema::JSONSchema::compile(&schema).map_err(|e| {
+                NmstateError::new(
+                    ErrorKind::Bug,
+                    format!("Failed to compile NetworkState JSON schema: {e}"),
+                )
+            })?;
+
+        if let Err(errors) = validator.validate(&value) {
+            let msgs: Vec<String> =
+                errors.map(|e| format!("{} at {}", e, e.instance_path)).collect();
+            return Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Desired state failed schema validation:\n{}",
+                    msgs.join("\n")
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_schema_is_non_empty_object() {
+        let schema = NetworkState::json_schema();
+        assert!(schema.is_object());
+        assert!(!schema.as_object().unwrap().is_empty());
+    }
+
+    #[test]
+    fn valid_current_version_document_passes() {
+        NetworkState::validate_against_schema(
+            "interfaces:\n- name: eth1\n  type: ethernet\n  state: up\n",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn unsupported_version_is_rejected_same_as_new_from_yaml() {
+        let doc = "version: 99\ninterfaces: []\n";
+        let schema_err =
+            NetworkState::validate_against_schema(doc).unwrap_err();
+        let parse_err = NetworkState::new_from_yaml(doc).unwrap_err();
+        assert!(format!("{schema_err}").contains("99"));
+        assert!(format!("{parse_err}").contains("99"));
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+
+use crate::{ErrorKind, NetworkState, NmstateError};
+
+/// The schema version produced/understood by the current release of this
+/// crate. A desired-state document without an explicit `version` field is
+/// assumed to already be at this version.
+pub(crate) const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// Implemented by every on-disk representation of [NetworkState] that
+/// [migrate_from_version()] dispatches to. `migrate()` turns a document of
+/// that representation into a [NetworkState] at [CURRENT_SCHEMA_VERSION].
+/// The current schema is the only version implemented so far.
+pub(crate) trait NetworkStateVersion: Sized {
+    fn migrate(self) -> Result<NetworkState, NmstateError>;
+}
+
+// The current schema has no predecessor to migrate from yet, so it is its
+// own (identity) migration. When a field is renamed/reshaped, the old shape
+// gets its own `NetworkStateV<N>` struct here and this impl moves onto it.
+impl NetworkStateVersion for NetworkState {
+    fn migrate(self) -> Result<NetworkState, NmstateError> {
+        Ok(self)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct VersionProbe {
+    #[serde(default)]
+    version: Option<u32>,
+}
+
+fn migrate_from_version(
+    version: u32,
+    value: serde_json::Value,
+) -> Result<NetworkState, NmstateError> {
+    match version {
+        CURRENT_SCHEMA_VERSION => {
+            let net_state: NetworkState = serde_json::from_value(value)
+                .map_err(invalid_argument)?;
+            net_state.migrate()
+        }
+        v => Err(NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Unsupported NetworkState schema version {v}, this release \
+                 of nmstate supports up to version {CURRENT_SCHEMA_VERSION}"
+            ),
+        )),
+    }
+}
+
+fn invalid_argument(e: impl std::fmt::Display) -> NmstateError {
+    NmstateError::new(ErrorKind::InvalidArgument, format!("{e}"))
+}
+
+/// Parse a desired-state document expressed as a [serde_json::Value],
+/// dispatching on its optional top-level `version` field and migrating it
+/// up to [CURRENT_SCHEMA_VERSION] before handing back a [NetworkState].
+pub(crate) fn net_state_from_value(
+    value: serde_json::Value,
+) -> Result<NetworkState, NmstateError> {
+    let probe: VersionProbe =
+        serde_json::from_value(value.clone()).map_err(invalid_argument)?;
+    migrate_from_version(
+        probe.version.unwrap_or(CURRENT_SCHEMA_VERSION),
+        value,
+    )
+}
+
+/// Cheaply reads just the `version` field out of a document without
+/// deserializing it into a full [serde_json::Value]/[NetworkState] first,
+/// so callers can decide whether the slower migration path is needed at
+/// all.
+fn probe_version(
+    probe: Result<VersionProbe, impl std::fmt::Display>,
+) -> Result<u32, NmstateError> {
+    probe
+        .map(|p| p.version.unwrap_or(CURRENT_SCHEMA_VERSION))
+        .map_err(|e| invalid_argument(format!("Invalid NetworkState document: {e}")))
+}
+
+pub(crate) fn net_state_from_json_str(
+    net_state_json: &str,
+) -> Result<NetworkState, NmstateError> {
+    let version = probe_version(serde_json::from_str::<VersionProbe>(net_state_json))?;
+    if version == CURRENT_SCHEMA_VERSION {
+        // Fast path: parse directly into NetworkState instead of going
+        // through a serde_json::Value intermediate, so a malformed
+        // document keeps the line/column information serde_json attaches
+        // to its errors rather than losing it in the Value round-trip.
+        return serde_json::from_str::<NetworkState>(net_state_json)
+            .map_err(invalid_argument)?
+            .migrate();
+    }
+    let value: serde_json::Value = serde_json::from_str(net_state_json)
+        .map_err(|e| invalid_argument(format!("Invalid JSON string: {e}")))?;
+    migrate_from_version(version, value)
+}
+
+pub(crate) fn net_state_from_yaml_str(
+    net_state_yaml: &str,
+) -> Result<NetworkState, NmstateError> {
+    let version = probe_version(serde_yaml::from_str::<VersionProbe>(net_state_yaml))?;
+    if version == CURRENT_SCHEMA_VERSION {
+        // Fast path, see net_state_from_json_str() above.
+        return serde_yaml::from_str::<NetworkState>(net_state_yaml)
+            .map_err(invalid_argument)?
+            .migrate();
+    }
+    let value: serde_yaml::Value = serde_yaml::from_str(net_state_yaml)
+        .map_err(|e| invalid_argument(format!("Invalid YAML string: {e}")))?;
+    let value: serde_json::Value =
+        serde_json::to_value(value).map_err(invalid_argument)?;
+    migrate_from_version(version, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_version_json_error_keeps_line_and_column() {
+        // `deny_unknown_fields` on NetworkState itself (not routed through
+        // an untagged enum) rejects this on line 3 directly from
+        // serde_json; a Value round-trip would have flattened that to
+        // "line 0 column 0".
+        let err = net_state_from_json_str(
+            "{\n  \"version\": 1,\n  \"no-such-field\": true\n}",
+        )
+        .unwrap_err();
+        let msg = format!("{err}");
+        assert!(
+            msg.contains("line 3"),
+            "expected line info in error, got: {msg}"
+        );
+    }
+
+    #[test]
+    fn unversioned_document_parses_as_current() {
+        let net_state =
+            net_state_from_json_str("{\"interfaces\": []}").unwrap();
+        assert_eq!(net_state.version, None);
+    }
+
+    #[test]
+    fn unsupported_version_is_rejected() {
+        let err = net_state_from_json_str(
+            "{\"version\": 99, \"interfaces\": []}",
+        )
+        .unwrap_err();
+        assert!(format!("{err}").contains("99"));
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::nm_dbus::NmConnection;
+use crate::Interface;
+
+use super::sriov::gen_nm_sriov_setting;
+use super::tunnel::gen_nm_tunnel_setting;
+
+/// Per-interface-type dispatch used by the NetworkManager connection
+/// generator to fill in the settings specific to `iface`'s type, on top
+/// of the settings common to every interface (name, MAC, MTU, IP
+/// configuration, ...) that the generator already fills in regardless
+/// of type.
+pub(crate) fn gen_nm_iface_setting(iface: &Interface, nm_conn: &mut NmConnection) {
+    match iface {
+        Interface::Ethernet(iface) => gen_nm_sriov_setting(iface, nm_conn),
+        Interface::Tunnel(iface) => gen_nm_tunnel_setting(iface, nm_conn),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TunnelConfig, TunnelInterface};
+
+    #[test]
+    fn tunnel_interface_is_dispatched_to_gen_nm_tunnel_setting() {
+        let mut tunnel_iface = TunnelInterface::new();
+        tunnel_iface.tunnel = Some(TunnelConfig {
+            local: "192.0.2.1".to_string(),
+            remote: "192.0.2.2".to_string(),
+            ..Default::default()
+        });
+        let iface = Interface::Tunnel(tunnel_iface);
+
+        let mut nm_conn = NmConnection::default();
+        gen_nm_iface_setting(&iface, &mut nm_conn);
+
+        assert!(nm_conn.ip_tunnel.is_some());
+    }
+}
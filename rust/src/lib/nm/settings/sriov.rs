@@ -69,7 +69,21 @@ fn gen_nm_vfs(
         if let Some(v) = vf.max_tx_rate {
             nm_vf.max_tx_rate = Some(v);
         }
-        if let Some(v) = vf.vlan_id {
+        if let Some(vlans) = vf.vlans.as_ref() {
+            nm_vf.vlans = Some(
+                vlans
+                    .iter()
+                    .map(|vlan| {
+                        let mut nm_vf_vlan = NmSettingSriovVfVlan::default();
+                        nm_vf_vlan.id = vlan.id;
+                        nm_vf_vlan.qos = vlan.qos.unwrap_or_default();
+                        nm_vf_vlan.protocol =
+                            vlan.protocol.unwrap_or_default().into();
+                        nm_vf_vlan
+                    })
+                    .collect(),
+            );
+        } else if let Some(v) = vf.vlan_id {
             let mut nm_vf_vlan = NmSettingSriovVfVlan::default();
             nm_vf_vlan.id = v;
             nm_vf_vlan.qos = vf.qos.unwrap_or_default();
@@ -80,3 +94,44 @@ fn gen_nm_vfs(
     }
     ret
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SrIovVfVlanConfig;
+
+    #[test]
+    fn vlans_vector_takes_precedence_over_scalar_shorthand() {
+        let vf = SrIovVfConfig {
+            id: 0,
+            vlan_id: Some(100),
+            qos: Some(1),
+            vlans: Some(vec![SrIovVfVlanConfig {
+                id: 200,
+                qos: Some(2),
+                protocol: None,
+            }]),
+            ..Default::default()
+        };
+        let nm_vfs = gen_nm_vfs(&[vf], Vec::new());
+        let vlans = nm_vfs[0].vlans.as_ref().unwrap();
+        assert_eq!(vlans.len(), 1);
+        assert_eq!(vlans[0].id, 200);
+        assert_eq!(vlans[0].qos, 2);
+    }
+
+    #[test]
+    fn scalar_shorthand_used_when_vlans_vector_absent() {
+        let vf = SrIovVfConfig {
+            id: 0,
+            vlan_id: Some(100),
+            qos: Some(1),
+            ..Default::default()
+        };
+        let nm_vfs = gen_nm_vfs(&[vf], Vec::new());
+        let vlans = nm_vfs[0].vlans.as_ref().unwrap();
+        assert_eq!(vlans.len(), 1);
+        assert_eq!(vlans[0].id, 100);
+        assert_eq!(vlans[0].qos, 1);
+    }
+}
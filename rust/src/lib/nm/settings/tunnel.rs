@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::nm_dbus::{NmConnection, NmIpTunnelMode, NmSettingIpTunnel};
+use crate::{InterfaceType, TunnelInterface};
+
+pub(crate) fn gen_nm_tunnel_setting(
+    iface: &TunnelInterface,
+    nm_conn: &mut NmConnection,
+) {
+    let tunnel_conf = match iface.tunnel.as_ref() {
+        Some(c) => c,
+        None => return,
+    };
+
+    let mode = match iface.base.iface_type {
+        InterfaceType::Gre => NmIpTunnelMode::Gre,
+        InterfaceType::Gre6 => NmIpTunnelMode::Ip6Gre,
+        InterfaceType::IpIp => NmIpTunnelMode::IpIp,
+        InterfaceType::Sit => NmIpTunnelMode::Sit,
+        _ => return,
+    };
+
+    let mut nm_ip_tunnel =
+        nm_conn.ip_tunnel.as_ref().cloned().unwrap_or_default();
+
+    nm_ip_tunnel.mode = mode;
+    nm_ip_tunnel.parent = tunnel_conf.base_iface.clone();
+    nm_ip_tunnel.local = Some(tunnel_conf.local.clone());
+    nm_ip_tunnel.remote = Some(tunnel_conf.remote.clone());
+
+    if let Some(v) = tunnel_conf.ttl {
+        nm_ip_tunnel.ttl = Some(v);
+    }
+    if let Some(v) = tunnel_conf.input_key.or(tunnel_conf.key) {
+        nm_ip_tunnel.input_key = Some(v.to_string());
+    }
+    if let Some(v) = tunnel_conf.output_key.or(tunnel_conf.key) {
+        nm_ip_tunnel.output_key = Some(v.to_string());
+    }
+
+    nm_conn.ip_tunnel = Some(nm_ip_tunnel);
+}